@@ -1,7 +1,197 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use base64::Engine as _;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
 use tauri::command;
+use tauri::ipc::Channel;
+use tauri::Manager;
+
+/// Client-level settings that determine which cached `reqwest::Client` a
+/// request uses: TLS options plus the connection/redirect policy, both of
+/// which can only be configured when a `reqwest::Client` is built.
+///
+/// Clients are expensive to build (they may load certs/keys from disk), so we
+/// key a small cache of them by this profile instead of building one per call.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct ClientProfile {
+    danger_accept_invalid_certs: bool,
+    client_cert_pem: Option<String>,
+    client_key_pem: Option<String>,
+    ca_cert_path: Option<String>,
+    connect_timeout_ms: Option<u64>,
+    follow_redirects: Option<bool>,
+    max_redirections: Option<usize>,
+}
+
+impl ClientProfile {
+    /// Builds a profile from the raw TLS/timeout/redirect fields, shared by
+    /// every request type that exposes them (`HttpRequest`, `UploadFileRequest`,
+    /// and the `esview://` query parameters).
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        danger_accept_invalid_certs: Option<bool>,
+        client_cert_pem: Option<String>,
+        client_key_pem: Option<String>,
+        ca_cert_path: Option<String>,
+        connect_timeout_ms: Option<u64>,
+        follow_redirects: Option<bool>,
+        max_redirections: Option<usize>,
+    ) -> Self {
+        ClientProfile {
+            danger_accept_invalid_certs: danger_accept_invalid_certs.unwrap_or(false),
+            client_cert_pem,
+            client_key_pem,
+            ca_cert_path,
+            connect_timeout_ms,
+            follow_redirects,
+            max_redirections,
+        }
+    }
+
+    fn from_request(request: &HttpRequest) -> Self {
+        Self::new(
+            request.danger_accept_invalid_certs,
+            request.client_cert_pem.clone(),
+            request.client_key_pem.clone(),
+            request.ca_cert_path.clone(),
+            request.connect_timeout_ms,
+            request.follow_redirects,
+            request.max_redirections,
+        )
+    }
+
+    fn build_client(&self) -> Result<reqwest::Client, String> {
+        let mut builder =
+            reqwest::Client::builder().danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+
+        if let (Some(cert_pem), Some(key_pem)) = (&self.client_cert_pem, &self.client_key_pem) {
+            let mut combined = cert_pem.clone();
+            combined.push('\n');
+            combined.push_str(key_pem);
+            let identity = reqwest::Identity::from_pem(combined.as_bytes())
+                .map_err(|e| format!("Invalid client certificate/key: {}", e))?;
+            builder = builder.identity(identity);
+        }
+
+        if let Some(ca_cert_path) = &self.ca_cert_path {
+            let pem = std::fs::read(ca_cert_path)
+                .map_err(|e| format!("Failed to read CA bundle '{}': {}", ca_cert_path, e))?;
+            let cert = reqwest::Certificate::from_pem(&pem)
+                .map_err(|e| format!("Invalid CA bundle '{}': {}", ca_cert_path, e))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        if let Some(connect_timeout_ms) = self.connect_timeout_ms {
+            builder = builder.connect_timeout(std::time::Duration::from_millis(connect_timeout_ms));
+        }
+
+        let redirect_policy = if self.follow_redirects == Some(false) {
+            reqwest::redirect::Policy::none()
+        } else if let Some(max) = self.max_redirections {
+            reqwest::redirect::Policy::limited(max)
+        } else {
+            reqwest::redirect::Policy::default()
+        };
+        builder = builder.redirect(redirect_policy);
+
+        builder
+            .build()
+            .map_err(|e| format!("Failed to build HTTP client: {}", e))
+    }
+}
+
+/// Caches one `reqwest::Client` per distinct `ClientProfile` (TLS options plus
+/// connect timeout and redirect policy) so connections (and TLS handshakes)
+/// can be pooled and reused across calls.
+///
+/// Cheaply cloneable (the cache is behind an `Arc`) so it can be captured by
+/// the `esview://` protocol handler, which runs outside of a `tauri::State`.
+#[derive(Clone, Default)]
+struct HttpClientManager {
+    clients: std::sync::Arc<Mutex<HashMap<ClientProfile, reqwest::Client>>>,
+}
+
+impl HttpClientManager {
+    fn client_for(&self, request: &HttpRequest) -> Result<reqwest::Client, String> {
+        self.client_for_profile(ClientProfile::from_request(request))
+    }
+
+    fn client_for_profile(&self, profile: ClientProfile) -> Result<reqwest::Client, String> {
+        let mut clients = self.clients.lock().unwrap();
+        if let Some(client) = clients.get(&profile) {
+            return Ok(client.clone());
+        }
+
+        let client = profile.build_client()?;
+        clients.insert(profile.clone(), client.clone());
+        Ok(client)
+    }
+}
+
+/// Tracks cancellation tokens for in-flight requests, keyed by the
+/// `request_id` the frontend attached to the `HttpRequest`, so a "Stop"
+/// button can abort a long-running query/scroll/export from `cancel_request`.
+///
+/// Entries carry a generation counter so that if the frontend reuses a
+/// `request_id` while the previous request with that id is still in flight,
+/// the older request's cleanup can't evict the newer request's token.
+#[derive(Default)]
+struct CancellationRegistry {
+    tokens: Mutex<HashMap<String, (u64, tokio_util::sync::CancellationToken)>>,
+    next_generation: std::sync::atomic::AtomicU64,
+}
+
+impl CancellationRegistry {
+    fn register(&self, request_id: &str) -> (u64, tokio_util::sync::CancellationToken) {
+        let token = tokio_util::sync::CancellationToken::new();
+        let generation = self
+            .next_generation
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.tokens
+            .lock()
+            .unwrap()
+            .insert(request_id.to_string(), (generation, token.clone()));
+        (generation, token)
+    }
+
+    /// Removes the entry for `request_id` only if it's still the one from
+    /// `generation` — i.e. nobody re-registered that id in the meantime.
+    fn unregister(&self, request_id: &str, generation: u64) {
+        let mut tokens = self.tokens.lock().unwrap();
+        if tokens.get(request_id).is_some_and(|(gen, _)| *gen == generation) {
+            tokens.remove(request_id);
+        }
+    }
+}
+
+#[command]
+fn cancel_request(request_id: String, cancellations: tauri::State<'_, CancellationRegistry>) -> bool {
+    match cancellations.tokens.lock().unwrap().remove(&request_id) {
+        Some((_, token)) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Races `fut` against `token`'s cancellation, if a token was registered for
+/// this request. Used to let `cancel_request` abort an in-flight `send().await`.
+async fn run_cancellable<T>(
+    token: Option<tokio_util::sync::CancellationToken>,
+    fut: impl std::future::Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    match token {
+        Some(token) => tokio::select! {
+            result = fut => result,
+            _ = token.cancelled() => Err("Request cancelled".to_string()),
+        },
+        None => fut.await,
+    }
+}
 
 #[derive(Debug, Deserialize)]
 pub struct HttpRequest {
@@ -9,65 +199,548 @@ pub struct HttpRequest {
     method: String,
     headers: Option<std::collections::HashMap<String, String>>,
     body: Option<String>,
+    /// Accept self-signed or otherwise invalid TLS certificates on the target cluster.
+    danger_accept_invalid_certs: Option<bool>,
+    /// PEM-encoded client certificate, for clusters that require mutual TLS.
+    client_cert_pem: Option<String>,
+    /// PEM-encoded private key matching `client_cert_pem`.
+    client_key_pem: Option<String>,
+    /// Path to a PEM-encoded CA bundle to trust, for clusters behind a private CA.
+    ca_cert_path: Option<String>,
+    /// How to decode the response body. Defaults to `Text`.
+    response_type: Option<ResponseType>,
+    /// Time allowed to establish the TCP+TLS connection, in milliseconds.
+    connect_timeout_ms: Option<u64>,
+    /// Time allowed for the whole request (connect + send + receive), in milliseconds.
+    timeout_ms: Option<u64>,
+    /// Whether to follow redirects at all. Defaults to `true`.
+    follow_redirects: Option<bool>,
+    /// Maximum number of redirects to follow when `follow_redirects` isn't `false`.
+    max_redirections: Option<usize>,
+    /// Number of retries for idempotent methods on connection errors, timeouts
+    /// or 5xx responses, using exponential backoff starting at 200ms.
+    retries: Option<u32>,
+    /// Opaque ID the frontend can later pass to `cancel_request` to abort
+    /// this request while it's in flight.
+    request_id: Option<String>,
+}
+
+/// Controls how `http_request` decodes the response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseType {
+    /// Decode the body as UTF-8 text (current, default behavior).
+    Text,
+    /// Parse the body as JSON and return it as a native value, avoiding a
+    /// double round-trip through `JSON.parse` on the frontend.
+    Json,
+    /// Read the raw bytes and return them base64-encoded, for binary payloads
+    /// (gzip'd responses, stored binary fields, attachments) that `.text()`
+    /// would otherwise corrupt.
+    Binary,
 }
 
 #[derive(Debug, Serialize)]
 pub struct HttpResponse {
     status: u16,
     ok: bool,
-    body: String,
+    headers: HashMap<String, String>,
+    body: serde_json::Value,
+    /// True when `body` is a base64-encoded string that the frontend must decode.
+    is_base64: bool,
 }
 
-#[command]
-async fn http_request(request: HttpRequest) -> Result<HttpResponse, String> {
-    let client = reqwest::Client::new();
-    
-    let method = match request.method.to_uppercase().as_str() {
-        "GET" => reqwest::Method::GET,
-        "POST" => reqwest::Method::POST,
-        "PUT" => reqwest::Method::PUT,
-        "DELETE" => reqwest::Method::DELETE,
-        "HEAD" => reqwest::Method::HEAD,
-        "PATCH" => reqwest::Method::PATCH,
-        _ => return Err(format!("Unsupported HTTP method: {}", request.method)),
-    };
+fn parse_method(method: &str) -> Result<reqwest::Method, String> {
+    match method.to_uppercase().as_str() {
+        "GET" => Ok(reqwest::Method::GET),
+        "POST" => Ok(reqwest::Method::POST),
+        "PUT" => Ok(reqwest::Method::PUT),
+        "DELETE" => Ok(reqwest::Method::DELETE),
+        "HEAD" => Ok(reqwest::Method::HEAD),
+        "PATCH" => Ok(reqwest::Method::PATCH),
+        _ => Err(format!("Unsupported HTTP method: {}", method)),
+    }
+}
 
+fn build_request(
+    client: &reqwest::Client,
+    request: &HttpRequest,
+) -> Result<reqwest::RequestBuilder, String> {
+    let method = parse_method(&request.method)?;
     let mut req_builder = client.request(method, &request.url);
 
     // Add headers
-    if let Some(headers) = request.headers {
+    if let Some(headers) = &request.headers {
         for (key, value) in headers {
-            req_builder = req_builder.header(&key, &value);
+            req_builder = req_builder.header(key, value);
         }
     }
 
     // Add body
-    if let Some(body) = request.body {
-        req_builder = req_builder.body(body);
+    if let Some(body) = &request.body {
+        req_builder = req_builder.body(body.clone());
+    }
+
+    if let Some(timeout_ms) = request.timeout_ms {
+        req_builder = req_builder.timeout(std::time::Duration::from_millis(timeout_ms));
+    }
+
+    Ok(req_builder)
+}
+
+/// Idempotent methods are safe to retry without risking duplicate side effects.
+fn is_idempotent(method: &reqwest::Method) -> bool {
+    matches!(
+        *method,
+        reqwest::Method::GET
+            | reqwest::Method::HEAD
+            | reqwest::Method::PUT
+            | reqwest::Method::DELETE
+    )
+}
+
+const RETRY_BASE_DELAY_MS: u64 = 200;
+const RETRY_MAX_DELAY_MS: u64 = 30_000;
+
+/// Delay before retry attempt `attempt` (1-indexed): 200ms, 400ms, 800ms, ...,
+/// capped at `RETRY_MAX_DELAY_MS` so a large caller-supplied `retries` can't
+/// overflow the exponent (and can't leave a cluster rebalance backing off for
+/// hours either).
+fn retry_delay(attempt: u32) -> std::time::Duration {
+    let exponent = attempt.saturating_sub(1).min(63);
+    let delay_ms = RETRY_BASE_DELAY_MS
+        .saturating_mul(1u64.checked_shl(exponent).unwrap_or(u64::MAX))
+        .min(RETRY_MAX_DELAY_MS);
+    std::time::Duration::from_millis(delay_ms)
+}
+
+/// Sends `req_builder`, retrying up to `retries` times with exponential
+/// backoff (200ms, 400ms, 800ms, ...) on connection-level failures, timeouts
+/// or 5xx responses, but only for idempotent methods.
+async fn send_with_retries(
+    req_builder: reqwest::RequestBuilder,
+    method: &reqwest::Method,
+    retries: u32,
+) -> Result<reqwest::Response, String> {
+    let retryable = is_idempotent(method);
+    let mut attempt = 0;
+
+    loop {
+        let builder = req_builder
+            .try_clone()
+            .ok_or_else(|| "Request body cannot be retried".to_string())?;
+
+        match builder.send().await {
+            Ok(response) if retryable && attempt < retries && response.status().is_server_error() => {
+                attempt += 1;
+                tokio::time::sleep(retry_delay(attempt)).await;
+            }
+            Ok(response) => return Ok(response),
+            Err(e) if retryable && attempt < retries && (e.is_connect() || e.is_timeout()) => {
+                attempt += 1;
+                tokio::time::sleep(retry_delay(attempt)).await;
+            }
+            Err(e) => return Err(format!("Request failed: {}", e)),
+        }
+    }
+}
+
+#[command]
+async fn http_request(
+    request: HttpRequest,
+    clients: tauri::State<'_, HttpClientManager>,
+    cancellations: tauri::State<'_, CancellationRegistry>,
+) -> Result<HttpResponse, String> {
+    let client = clients.client_for(&request)?;
+    let response_type = request.response_type.unwrap_or(ResponseType::Text);
+    let method = parse_method(&request.method)?;
+    let retries = request.retries.unwrap_or(0);
+    let req_builder = build_request(&client, &request)?;
+    let registration = request.request_id.as_ref().map(|id| cancellations.register(id));
+    let token = registration.as_ref().map(|(_, token)| token.clone());
+
+    let result = run_cancellable(token, async {
+        // Send request
+        let response = send_with_retries(req_builder, &method, retries).await?;
+
+        let status = response.status().as_u16();
+        let ok = response.status().is_success();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    value.to_str().unwrap_or_default().to_string(),
+                )
+            })
+            .collect();
+
+        let (body, is_base64) = match response_type {
+            ResponseType::Text => {
+                let text = response
+                    .text()
+                    .await
+                    .map_err(|e| format!("Failed to read response body: {}", e))?;
+                (serde_json::Value::String(text), false)
+            }
+            ResponseType::Json => {
+                let value = response
+                    .json::<serde_json::Value>()
+                    .await
+                    .map_err(|e| format!("Failed to parse response body as JSON: {}", e))?;
+                (value, false)
+            }
+            ResponseType::Binary => {
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|e| format!("Failed to read response body: {}", e))?;
+                let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+                (serde_json::Value::String(encoded), true)
+            }
+        };
+
+        Ok(HttpResponse {
+            status,
+            ok,
+            headers,
+            body,
+            is_base64,
+        })
+    })
+    .await;
+
+    if let (Some(request_id), Some((generation, _))) = (&request.request_id, &registration) {
+        cancellations.unregister(request_id, *generation);
+    }
+
+    result
+}
+
+/// Messages sent to the frontend over the `Channel` passed to `http_request_stream`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+enum StreamEvent {
+    #[serde(rename_all = "camelCase")]
+    Progress {
+        /// Base64-encoded raw bytes of this chunk. Chunk boundaries fall at
+        /// arbitrary byte offsets, so decoding each chunk as UTF-8 on its own
+        /// would corrupt any multi-byte character split across two chunks;
+        /// base64 lets the frontend concatenate first and decode once.
+        chunk: String,
+        downloaded: u64,
+        total: Option<u64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Done { status: u16, ok: bool },
+}
+
+/// Streaming variant of `http_request` that forwards the response body to the
+/// frontend as a series of `progress` events instead of buffering it all into
+/// memory, so large ES exports/scrolls don't block on one giant IPC payload.
+#[command]
+async fn http_request_stream(
+    request: HttpRequest,
+    clients: tauri::State<'_, HttpClientManager>,
+    cancellations: tauri::State<'_, CancellationRegistry>,
+    channel: Channel<StreamEvent>,
+) -> Result<(), String> {
+    let client = clients.client_for(&request)?;
+    let method = parse_method(&request.method)?;
+    let retries = request.retries.unwrap_or(0);
+    let req_builder = build_request(&client, &request)?;
+    let registration = request.request_id.as_ref().map(|id| cancellations.register(id));
+    let token = registration.as_ref().map(|(_, token)| token.clone());
+
+    let result = run_cancellable(token, async {
+        let response = send_with_retries(req_builder, &method, retries).await?;
+
+        let status = response.status().as_u16();
+        let ok = response.status().is_success();
+        let total = response.content_length();
+
+        let mut downloaded: u64 = 0;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Failed to read response body: {}", e))?;
+            downloaded += chunk.len() as u64;
+            channel
+                .send(StreamEvent::Progress {
+                    chunk: base64::engine::general_purpose::STANDARD.encode(&chunk),
+                    downloaded,
+                    total,
+                })
+                .map_err(|e| format!("Failed to send progress event: {}", e))?;
+        }
+
+        channel
+            .send(StreamEvent::Done { status, ok })
+            .map_err(|e| format!("Failed to send done event: {}", e))?;
+
+        Ok(())
+    })
+    .await;
+
+    if let (Some(request_id), Some((generation, _))) = (&request.request_id, &registration) {
+        cancellations.unregister(request_id, *generation);
+    }
+
+    result
+}
+
+/// Request body for `http_request_upload_file`.
+#[derive(Debug, Deserialize)]
+pub struct UploadFileRequest {
+    url: String,
+    /// Defaults to `POST`.
+    method: Option<String>,
+    headers: Option<HashMap<String, String>>,
+    /// Local path of the file to stream as the request body, e.g. an NDJSON
+    /// file destined for Elasticsearch's `_bulk` endpoint.
+    file_path: String,
+    /// Defaults to `application/x-ndjson`, the content type ES's `_bulk` expects.
+    content_type: Option<String>,
+    /// Gzip-compress the request body while streaming it.
+    gzip: Option<bool>,
+    /// Accept self-signed or otherwise invalid TLS certificates on the target cluster.
+    danger_accept_invalid_certs: Option<bool>,
+    /// PEM-encoded client certificate, for clusters that require mutual TLS.
+    client_cert_pem: Option<String>,
+    /// PEM-encoded private key matching `client_cert_pem`.
+    client_key_pem: Option<String>,
+    /// Path to a PEM-encoded CA bundle to trust, for clusters behind a private CA.
+    ca_cert_path: Option<String>,
+    /// Time allowed to establish the TCP+TLS connection, in milliseconds.
+    connect_timeout_ms: Option<u64>,
+    /// Whether to follow redirects at all. Defaults to `true`.
+    follow_redirects: Option<bool>,
+    /// Maximum number of redirects to follow when `follow_redirects` isn't `false`.
+    max_redirections: Option<usize>,
+}
+
+impl UploadFileRequest {
+    fn client_profile(&self) -> ClientProfile {
+        ClientProfile::new(
+            self.danger_accept_invalid_certs,
+            self.client_cert_pem.clone(),
+            self.client_key_pem.clone(),
+            self.ca_cert_path.clone(),
+            self.connect_timeout_ms,
+            self.follow_redirects,
+            self.max_redirections,
+        )
+    }
+}
+
+/// Messages sent to the frontend over the `Channel` passed to `http_request_upload_file`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+enum UploadEvent {
+    #[serde(rename_all = "camelCase")]
+    Progress { uploaded: u64, total: u64 },
+    #[serde(rename_all = "camelCase")]
+    Done { status: u16, ok: bool },
+}
+
+/// Streams a request body from a local file instead of requiring the whole
+/// payload up front in `HttpRequest.body`, so importing a multi-gigabyte
+/// NDJSON bulk file doesn't first have to be loaded into JS memory and then
+/// into Rust. Upload progress is reported over `channel` as the file is read.
+#[command]
+async fn http_request_upload_file(
+    request: UploadFileRequest,
+    clients: tauri::State<'_, HttpClientManager>,
+    channel: Channel<UploadEvent>,
+) -> Result<(), String> {
+    let client = clients.client_for_profile(request.client_profile())?;
+    let method = parse_method(request.method.as_deref().unwrap_or("POST"))?;
+
+    let total = tokio::fs::metadata(&request.file_path)
+        .await
+        .map_err(|e| format!("Failed to stat '{}': {}", request.file_path, e))?
+        .len();
+    let file = tokio::fs::File::open(&request.file_path)
+        .await
+        .map_err(|e| format!("Failed to open '{}': {}", request.file_path, e))?;
+
+    let uploaded = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let progress_channel = channel.clone();
+    let progress_uploaded = uploaded.clone();
+    let file_stream = tokio_util::io::ReaderStream::new(file).map(move |chunk| {
+        if let Ok(chunk) = &chunk {
+            let so_far = progress_uploaded
+                .fetch_add(chunk.len() as u64, std::sync::atomic::Ordering::Relaxed)
+                + chunk.len() as u64;
+            let _ = progress_channel.send(UploadEvent::Progress {
+                uploaded: so_far,
+                total,
+            });
+        }
+        chunk
+    });
+
+    let gzip = request.gzip.unwrap_or(false);
+    let body = if gzip {
+        let reader = tokio_util::io::StreamReader::new(file_stream);
+        let encoder = async_compression::tokio::bufread::GzipEncoder::new(reader);
+        reqwest::Body::wrap_stream(tokio_util::io::ReaderStream::new(encoder))
+    } else {
+        reqwest::Body::wrap_stream(file_stream)
+    };
+
+    let content_type = request
+        .content_type
+        .unwrap_or_else(|| "application/x-ndjson".to_string());
+
+    let mut req_builder = client
+        .request(method, &request.url)
+        .header(reqwest::header::CONTENT_TYPE, content_type);
+
+    if gzip {
+        req_builder = req_builder.header(reqwest::header::CONTENT_ENCODING, "gzip");
+    }
+
+    if let Some(headers) = &request.headers {
+        for (key, value) in headers {
+            req_builder = req_builder.header(key, value);
+        }
     }
 
-    // Send request
     let response = req_builder
+        .body(body)
         .send()
         .await
-        .map_err(|e| format!("Request failed: {}", e))?;
+        .map_err(|e| format!("Upload failed: {}", e))?;
 
     let status = response.status().as_u16();
     let ok = response.status().is_success();
+
+    channel
+        .send(UploadEvent::Done { status, ok })
+        .map_err(|e| format!("Failed to send done event: {}", e))?;
+
+    Ok(())
+}
+
+/// Reads the same TLS/timeout/redirect settings `HttpRequest` exposes from the
+/// `esview://` URI's query parameters, so the protocol handler can reach
+/// self-signed or private-CA clusters instead of always using the default
+/// client (which would fail the handshake against exactly those clusters).
+fn client_profile_from_query(parsed: &tauri::Url) -> ClientProfile {
+    let param = |key: &str| -> Option<String> {
+        parsed
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+
+    ClientProfile::new(
+        param("danger_accept_invalid_certs").and_then(|v| v.parse().ok()),
+        param("client_cert_pem"),
+        param("client_key_pem"),
+        param("ca_cert_path"),
+        param("connect_timeout_ms").and_then(|v| v.parse().ok()),
+        param("follow_redirects").and_then(|v| v.parse().ok()),
+        param("max_redirections").and_then(|v| v.parse().ok()),
+    )
+}
+
+/// Builds the upstream `reqwest` request for an `esview://` protocol call.
+///
+/// The target ES URL is read from the `url` query parameter (e.g.
+/// `esview://fetch?url=https://es.internal:9200/index/_doc/1`); method, headers
+/// and body are proxied as-is from the incoming webview request, with the
+/// `Host` header dropped so it gets set to match the upstream URL instead.
+fn build_upstream_request(
+    client: &reqwest::Client,
+    parsed: &tauri::Url,
+    request: &tauri::http::Request<Vec<u8>>,
+) -> Result<reqwest::RequestBuilder, String> {
+    let upstream_url = parsed
+        .query_pairs()
+        .find(|(key, _)| key == "url")
+        .map(|(_, value)| value.into_owned())
+        .ok_or_else(|| "esview:// URI is missing a 'url' query parameter".to_string())?;
+
+    let method = reqwest::Method::from_bytes(request.method().as_str().as_bytes())
+        .map_err(|e| format!("Invalid method '{}': {}", request.method(), e))?;
+
+    let mut req_builder = client.request(method, &upstream_url);
+    for (name, value) in request.headers() {
+        if name == tauri::http::header::HOST {
+            continue;
+        }
+        if let Ok(value) = value.to_str() {
+            req_builder = req_builder.header(name.as_str(), value);
+        }
+    }
+
+    if !request.body().is_empty() {
+        req_builder = req_builder.body(request.body().clone());
+    }
+
+    Ok(req_builder)
+}
+
+/// Proxies an `esview://` request to Elasticsearch and returns the response
+/// status, headers (including `Content-Type`/`Content-Range`), and body.
+async fn fetch_esview(
+    clients: HttpClientManager,
+    request: tauri::http::Request<Vec<u8>>,
+) -> Result<tauri::http::Response<Vec<u8>>, String> {
+    let parsed = tauri::Url::parse(&request.uri().to_string())
+        .map_err(|e| format!("Invalid esview:// URI '{}': {}", request.uri(), e))?;
+    let client = clients.client_for_profile(client_profile_from_query(&parsed))?;
+    let req_builder = build_upstream_request(&client, &parsed, &request)?;
+
+    let response = req_builder
+        .send()
+        .await
+        .map_err(|e| format!("esview:// request failed: {}", e))?;
+
+    let mut builder = tauri::http::Response::builder().status(response.status().as_u16());
+    for (name, value) in response.headers() {
+        builder = builder.header(name.as_str(), value.as_bytes());
+    }
+
     let body = response
-        .text()
+        .bytes()
         .await
-        .map_err(|e| format!("Failed to read response body: {}", e))?;
+        .map_err(|e| format!("Failed to read esview:// response body: {}", e))?;
 
-    Ok(HttpResponse { status, ok, body })
+    builder
+        .body(body.to_vec())
+        .map_err(|e| format!("Failed to build esview:// response: {}", e))
 }
 
 fn main() {
     tauri::Builder::default()
+        .manage(HttpClientManager::default())
+        .manage(CancellationRegistry::default())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
-        .invoke_handler(tauri::generate_handler![http_request])
+        .register_asynchronous_uri_scheme_protocol("esview", |app, request, responder| {
+            let clients = app.state::<HttpClientManager>().inner().clone();
+            tauri::async_runtime::spawn(async move {
+                match fetch_esview(clients, request).await {
+                    Ok(response) => responder.respond(response),
+                    Err(e) => responder.respond(
+                        tauri::http::Response::builder()
+                            .status(tauri::http::StatusCode::BAD_GATEWAY)
+                            .body(e.into_bytes())
+                            .unwrap(),
+                    ),
+                }
+            });
+        })
+        .invoke_handler(tauri::generate_handler![
+            http_request,
+            http_request_stream,
+            http_request_upload_file,
+            cancel_request
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }